@@ -0,0 +1,171 @@
+//! Owning and borrowing fat-pointer wrappers around [`VtObject`].
+
+use ::core::{
+	marker::PhantomData,
+	ops::{Deref, DerefMut},
+};
+
+use ::alloc::boxed::Box;
+
+use crate::{VtObject, VtObjectPtr};
+
+/// Trait implemented automatically for VTable types declared with a
+/// `fn drop();` destructor item (see [`vtable!`](crate::vtable)), letting
+/// [`VtBox`] call the virtual destructor before freeing its backing storage.
+pub trait HasVirtualDestructor {
+	/// Invoke the virtual destructor on `obj`.
+	///
+	/// # Safety
+	/// Same requirements as [`virtual_destruct!`](crate::virtual_destruct).
+	unsafe fn virtual_destruct(obj: &mut VtObject<Self>)
+	where
+		Self: Sized;
+}
+
+/// Borrowed immutable access to a C++ object with a `VTable`, bundling a
+/// [`VtObjectPtr`] with the lifetime of the borrow.
+///
+/// Derefs to [`VtObject<VTable>`] immutably only. The methods of a companion
+/// trait (see [`vtable!`](crate::vtable)'s `trait` option) take `&mut self`
+/// (or `Pin<&mut Self>`), so they cannot be called through a `VtRef`; use
+/// [`virtual_call!`](crate::virtual_call) on `*pet` instead, or a [`VtRefMut`].
+pub struct VtRef<'a, VTable> {
+	ptr: VtObjectPtr<VTable>,
+	_marker: PhantomData<&'a VtObject<VTable>>,
+}
+
+impl<'a, VTable> VtRef<'a, VTable> {
+	/// Wrap `ptr` as borrowed for the duration of `'a`.
+	///
+	/// # Safety
+	/// Same requirements as [`VtObject::from_ptr_const`].
+	pub const unsafe fn new(ptr: VtObjectPtr<VTable>) -> Self {
+		Self { ptr, _marker: PhantomData }
+	}
+}
+
+impl<'a, VTable> Deref for VtRef<'a, VTable> {
+	type Target = VtObject<VTable>;
+
+	fn deref(&self) -> &Self::Target {
+		// SAFETY: `self.ptr` was established to be valid for `'a` by `new`.
+		unsafe { VtObject::from_ptr_const(self.ptr) }
+	}
+}
+
+/// Borrowed mutable access to a C++ object with a `VTable`, bundling a
+/// [`VtObjectPtr`] with the lifetime of the borrow.
+///
+/// Derefs to [`VtObject<VTable>`], so if `VTable` was declared with a
+/// companion trait (see [`vtable!`](crate::vtable)'s `trait` option), that
+/// trait's methods can be called directly (e.g. `pet.speak()`) instead of
+/// going through [`virtual_call!`](crate::virtual_call). A trait declared
+/// with the `pin` option takes a pinned receiver instead, so its methods are
+/// called as `Pin::new(&mut *pet).speak()`.
+pub struct VtRefMut<'a, VTable> {
+	ptr: VtObjectPtr<VTable>,
+	_marker: PhantomData<&'a mut VtObject<VTable>>,
+}
+
+impl<'a, VTable> VtRefMut<'a, VTable> {
+	/// Wrap `ptr` as mutably borrowed for the duration of `'a`.
+	///
+	/// # Safety
+	/// Same requirements as [`VtObject::from_ptr_mut`].
+	pub const unsafe fn new(ptr: VtObjectPtr<VTable>) -> Self {
+		Self { ptr, _marker: PhantomData }
+	}
+}
+
+impl<'a, VTable> Deref for VtRefMut<'a, VTable> {
+	type Target = VtObject<VTable>;
+
+	fn deref(&self) -> &Self::Target {
+		// SAFETY: `self.ptr` was established to be valid for `'a` by `new`.
+		unsafe { VtObject::from_ptr_const(self.ptr) }
+	}
+}
+
+impl<'a, VTable> DerefMut for VtRefMut<'a, VTable> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		// SAFETY: `self.ptr` was established to be validly borrowable as
+		// `&mut` for `'a` by `new`.
+		unsafe { VtObject::from_ptr_mut(self.ptr) }
+	}
+}
+
+/// Owning fat pointer to a C++ object with a `VTable`: a heap-allocated Rust
+/// value paired with the `VTable` it was constructed with, freed (after
+/// running the virtual destructor) on drop.
+///
+/// Only available for VTables declared with a `fn drop();` destructor item,
+/// since that is what lets [`VtBox`] know how to tear the object down; see
+/// [`HasVirtualDestructor`].
+///
+/// Derefs to [`VtObject<VTable>`], so if `VTable` was declared with a
+/// companion trait (see [`vtable!`](crate::vtable)'s `trait` option), that
+/// trait's methods can be called directly (e.g. `pet.speak()`) instead of
+/// going through [`virtual_call!`](crate::virtual_call). A trait declared
+/// with the `pin` option takes a pinned receiver instead, so its methods are
+/// called as `Pin::new(&mut *pet).speak()`.
+pub struct VtBox<VTable: HasVirtualDestructor> {
+	ptr: VtObjectPtr<VTable>,
+	drop_in_place: unsafe fn(VtObjectPtr<VTable>),
+}
+
+impl<VTable: HasVirtualDestructor> VtBox<VTable> {
+	/// Move `value` to the heap, producing an owning fat pointer to it.
+	///
+	/// # Safety
+	/// `T` must be `#[repr(C)]` with a [`VTablePtr<VTable>`](crate::VTablePtr)
+	/// as its first field, already set to a valid `'static` VTable, the same
+	/// way [`VtObjectPtr`] itself requires. This is what lets `value`'s own
+	/// address double as the `VtObjectPtr` passed to the VTable's thunks, e.g.
+	/// the ones generated by a [`vtable!`](crate::vtable)-declared
+	/// `static_ctor` macro, whose thunks cast that pointer straight back to
+	/// `&mut Self` with no offset.
+	pub unsafe fn new<T>(value: T) -> Self {
+		let raw = Box::into_raw(Box::new(value));
+
+		// SAFETY: caller guarantees `T` has a `VTablePtr<VTable>` as its
+		// first field, so a pointer to `T` doubles as a valid
+		// `VtObjectPtr<VTable>`.
+		let ptr = unsafe { VtObjectPtr::new_unchecked(raw.cast()) };
+
+		Self {
+			ptr,
+			drop_in_place: |ptr| {
+				// SAFETY: `ptr` was produced from `Box::into_raw` above, and
+				// is only ever freed once, here, when the `VtBox` is dropped.
+				unsafe { drop(Box::from_raw(ptr.cast::<T>().as_ptr())) }
+			},
+		}
+	}
+}
+
+impl<VTable: HasVirtualDestructor> Deref for VtBox<VTable> {
+	type Target = VtObject<VTable>;
+
+	fn deref(&self) -> &Self::Target {
+		// SAFETY: `self.ptr` is valid for as long as the `VtBox` is alive.
+		unsafe { VtObject::from_ptr_const(self.ptr) }
+	}
+}
+
+impl<VTable: HasVirtualDestructor> DerefMut for VtBox<VTable> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		// SAFETY: `self.ptr` is valid for as long as the `VtBox` is alive,
+		// and `&mut self` ensures exclusive access.
+		unsafe { VtObject::from_ptr_mut(self.ptr) }
+	}
+}
+
+impl<VTable: HasVirtualDestructor> Drop for VtBox<VTable> {
+	fn drop(&mut self) {
+		// SAFETY: `self.ptr` is valid and uniquely owned by this `VtBox`.
+		unsafe {
+			VTable::virtual_destruct(&mut **self);
+			(self.drop_in_place)(self.ptr);
+		}
+	}
+}
@@ -0,0 +1,112 @@
+//! Itanium C++ ABI details that live immediately *before* a polymorphic
+//! object's VTable, rather than in the VTable itself.
+//!
+//! A [`VtObjectPtr`](crate::VtObjectPtr) only ever points at a class's
+//! function-pointer slots (the "address point" of the VTable, in Itanium ABI
+//! terms). But the compiler always reserves two hidden words directly before
+//! that address: an offset-to-top, and a pointer to the class's
+//! `std::type_info`. This module models that prologue and the downcast it
+//! makes possible.
+//!
+//! This is specific to `cfg(not(all(windows, target_arch = "x86")))` targets,
+//! i.e. wherever [`vtable!`](crate::vtable) itself uses the Itanium ABI; the
+//! 32-bit MSVC ABI has no such prologue at all. Since `vtable!` has no way to
+//! know which ABI a given VTable was actually emitted under, VTables have to
+//! opt in to this module's functionality with an `itanium;` item (see
+//! [`ItaniumVt`]) rather than getting it unconditionally.
+
+use ::core::ffi::c_char;
+
+/// Opaque representation of an Itanium ABI `std::type_info` object.
+///
+/// Only the first two fields of the real `std::type_info` are modeled here
+/// (its own VTable pointer, and its `name`), which is all that is needed to
+/// tell two `type_info`s apart: first by pointer identity, which holds
+/// whenever both sides come from the same linked image, falling back to a
+/// `name` comparison for the cross-shared-library case, the same way
+/// libstdc++'s own `type_info::operator==` does.
+#[repr(C)]
+pub struct TypeInfo {
+	_vtable: *const (),
+	name: *const c_char,
+}
+
+impl TypeInfo {
+	/// Return whether `self` and `other` identify the same C++ class.
+	pub fn is_same(&self, other: &TypeInfo) -> bool {
+		if ::core::ptr::eq(self, other) {
+			return true;
+		}
+		// SAFETY: both `name`s are non-null, null-terminated strings for the
+		// `'static` lifetime of their `type_info`, per the Itanium C++ ABI.
+		unsafe { c_str_eq(self.name, other.name) }
+	}
+}
+
+unsafe fn c_str_eq(a: *const c_char, b: *const c_char) -> bool {
+	let mut i = 0isize;
+	loop {
+		// SAFETY: caller guarantees both are null-terminated C strings.
+		let (byte_a, byte_b) = unsafe { (*a.offset(i), *b.offset(i)) };
+		if byte_a != byte_b {
+			return false;
+		}
+		if byte_a == 0 {
+			return true;
+		}
+		i += 1;
+	}
+}
+
+/// The hidden prologue that the Itanium C++ ABI places directly before a
+/// polymorphic object's VTable function slots.
+#[repr(C)]
+pub struct Prologue {
+	/// Offset, in bytes, to add to the object pointer holding this VTable in
+	/// order to recover a pointer to the most-derived object.
+	pub offset_to_top: isize,
+	/// Pointer to the `type_info` of the object's dynamic type.
+	pub type_info: *const TypeInfo,
+}
+
+/// Marker trait opted into via [`vtable!`](crate::vtable)'s `itanium;` item,
+/// asserting that the VTable carries the hidden [`Prologue`] directly before
+/// its function slots, the way a real Itanium-ABI C++ compiler emits it.
+///
+/// [`VtObject::vtable_prologue`](crate::VtObject::vtable_prologue) and
+/// [`VtObject::downcast`](crate::VtObject::downcast) are only available for
+/// VTables that implement this, since the prologue isn't there at all on
+/// VTables that might run under the 32-bit MSVC ABI.
+pub trait ItaniumVt {}
+
+/// Associates a [`vtable!`](crate::vtable)-declared VTable type with the
+/// `type_info` that identifies the C++ class it belongs to, so that
+/// [`VtObject::downcast`](crate::VtObject::downcast) can recognize it as a
+/// downcast target.
+///
+/// Implementations are expected to be written by hand, the same way the
+/// rest of this crate expects `extern` thunks to be written by hand: there is
+/// no portable way to synthesize a C++ `typeid` expression's mangled symbol
+/// from Rust, so getting hold of the `&'static TypeInfo` is left to whatever
+/// FFI glue already exposes it.
+pub trait VtTypeInfo {
+	/// Return the `type_info` identifying this exact class.
+	fn type_info() -> &'static TypeInfo;
+
+	/// Return the `type_info`s of this class's known intermediate base
+	/// classes, in case the object's dynamic type is a base of `Self` rather
+	/// than `Self` exactly.
+	///
+	/// The default implementation returns an empty slice, i.e. only an exact
+	/// dynamic-type match is recognized.
+	///
+	/// # Limitations
+	/// This does not walk the full Itanium `__vmi_class_type_info` base
+	/// array of the *dynamic* type, so [`downcast`](crate::VtObject::downcast)
+	/// cannot see through multiple or virtual inheritance on its own; listing
+	/// the relevant intermediate bases here is what makes those downcasts
+	/// succeed.
+	fn base_type_infos() -> &'static [&'static TypeInfo] {
+		&[]
+	}
+}
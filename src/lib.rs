@@ -2,15 +2,31 @@
 //! 
 //! This crate does not use any proc macros; all of the macros use `macro_rules!`.
 
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 #![allow(clippy::tabs_in_doc_comments)]
 
+extern crate alloc;
+
 use ::core::{
 	fmt,
 	ptr::NonNull,
 };
 
 mod macros;
+mod itanium;
+mod fat_ptr;
+
+pub use itanium::{ItaniumVt, Prologue, TypeInfo, VtTypeInfo};
+pub use fat_ptr::{HasVirtualDestructor, VtBox, VtRef, VtRefMut};
+
+/// Items re-exported for [`vtable!`]-generated code to refer to without
+/// requiring the caller to have them in scope.
+///
+/// Not part of the public API.
+#[doc(hidden)]
+pub mod _macro_reexport {
+	pub use ::core::{pin::Pin, ptr::NonNull};
+}
 
 /// Type for virtual function table pointers.
 pub type VTablePtr<VTable> = NonNull<VTable>;
@@ -78,7 +94,7 @@ impl<VTable> VtObject<VTable> {
 	}
 
 	/// Return a mutable reference to the object's `VTable`.
-	/// 
+	///
 	/// # Safety
 	/// The `VTable` is usually not intended to be modified,
 	/// and all sorts of Undefined Behavior may arise from its modification.
@@ -86,4 +102,126 @@ impl<VTable> VtObject<VTable> {
 		// SAFETY: `vtable` is always valid.
 		unsafe { self.vtable.as_mut() }
 	}
+
+}
+
+/// Methods available only for VTables declared with [`vtable!`]'s `itanium;`
+/// item (see [`ItaniumVt`]), since they assume the Itanium ABI's hidden
+/// prologue is actually there before the VTable's function slots.
+impl<VTable: ItaniumVt> VtObject<VTable> {
+	/// Return a reference to the [`Prologue`] that the Itanium C++ ABI places
+	/// directly before this object's VTable function slots.
+	///
+	/// # Safety
+	/// The VTable pointed to by this object must have been emitted by a real
+	/// Itanium-ABI C++ compiler, which always reserves this prologue, even
+	/// though [`vtable!`] itself has no notion of it.
+	pub const unsafe fn vtable_prologue(&self) -> &Prologue {
+		unsafe { self.vtable.cast::<Prologue>().as_ptr().offset(-1).as_ref().unwrap_unchecked() }
+	}
+
+	/// Attempt to downcast this object to `Derived`, implementing the
+	/// essentials of the Itanium ABI's `__dynamic_cast`: compare the
+	/// object's runtime `type_info` (from its [`vtable_prologue`](Self::vtable_prologue))
+	/// against `Derived`'s, then apply the offset-to-top to recover a pointer
+	/// to the most-derived object.
+	///
+	/// Returns `None` if the runtime `type_info` does not identify `Derived`
+	/// or one of the base classes it lists via [`VtTypeInfo::base_type_infos`].
+	///
+	/// # Safety
+	/// The VTable pointed to by this object must have been emitted by a real
+	/// Itanium-ABI C++ compiler.
+	pub unsafe fn downcast<Derived: VtTypeInfo>(&self) -> Option<VtObjectPtr<Derived>> {
+		let prologue = unsafe { self.vtable_prologue() };
+		// SAFETY: `type_info` is always valid, per the Itanium C++ ABI.
+		let runtime_info = unsafe { &*prologue.type_info };
+		let target = Derived::type_info();
+		let matches = runtime_info.is_same(target)
+			|| Derived::base_type_infos().iter().any(|base| runtime_info.is_same(base));
+		if !matches {
+			return None;
+		}
+		// SAFETY: `offset_to_top` locates the start of the most-derived
+		// object relative to `self`, per the Itanium C++ ABI.
+		let adjusted = unsafe { (self.as_ptr().as_ptr() as *mut u8).offset(prologue.offset_to_top) };
+		NonNull::new(adjusted.cast())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ::core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+	use crate::{vtable, new_vtable_self, VTablePtr, VtObject, VtObjectPtr, VtBox};
+
+	vtable! {
+		PetVt trait Pet static_ctor pet_vt_static {
+			pub fn speak() -> u32;
+		}
+	}
+
+	#[repr(C)]
+	struct Cat {
+		vtable: VTablePtr<PetVt>,
+		spoken: AtomicU32,
+	}
+
+	impl Pet for Cat {
+		fn speak(&mut self) -> u32 {
+			self.spoken.fetch_add(1, Ordering::SeqCst) + 1
+		}
+	}
+
+	pet_vt_static! { CAT_VT for Cat }
+
+	#[test]
+	fn generated_trait_dispatches_through_the_vtable() {
+		let mut cat = Cat { vtable: VTablePtr::from(&CAT_VT), spoken: AtomicU32::new(0) };
+		// SAFETY: `Cat` starts with the `VTablePtr<PetVt>` that
+		// `VtObjectPtr`/`VtObject` require, set to the real `CAT_VT`.
+		let this: VtObjectPtr<PetVt> = unsafe {
+			VtObjectPtr::new_unchecked((&mut cat as *mut Cat).cast())
+		};
+		// SAFETY: `this` points at the live `cat` above for the rest of this test.
+		let pet = unsafe { VtObject::from_ptr_mut(this) };
+		assert_eq!(pet.speak(), 1);
+		assert_eq!(pet.speak(), 2);
+	}
+
+	vtable! {
+		DropVt {
+			pub fn drop();
+		}
+	}
+
+	#[repr(C)]
+	struct Thing {
+		vtable: VTablePtr<DropVt>,
+		dropped: *const AtomicBool,
+	}
+
+	impl Thing {
+		unsafe extern "C-unwind" fn complete_dtor(this: VtObjectPtr<DropVt>) {
+			// SAFETY: `this` is the `VtObjectPtr` of a live `Thing`, per the
+			// `VtBox` invariant that constructed it.
+			let this = unsafe { this.cast::<Thing>().as_ref() };
+			// SAFETY: `dropped` is a live `AtomicBool` for as long as `Thing` is.
+			unsafe { &*this.dropped }.store(true, Ordering::SeqCst);
+		}
+
+		unsafe extern "C-unwind" fn deleting_dtor(_this: VtObjectPtr<DropVt>) {}
+	}
+
+	static THING_VT: DropVt = new_vtable_self!(DropVt for Thing { complete_dtor, deleting_dtor });
+
+	#[test]
+	fn vtbox_runs_the_virtual_destructor_on_drop() {
+		let dropped = AtomicBool::new(false);
+		let thing: VtBox<DropVt> = unsafe {
+			VtBox::new(Thing { vtable: VTablePtr::from(&THING_VT), dropped: &dropped })
+		};
+		drop(thing);
+		assert!(dropped.load(Ordering::SeqCst));
+	}
 }
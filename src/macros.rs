@@ -9,10 +9,30 @@
 /// # Function order
 /// VTable functions must be defined in order of their appearance in the header
 /// file of the class they are defined in.
-/// 
-/// Do keep in mind that on `cfg(not(windows))`, there are *two*
-/// virtual destructors.
-/// 
+///
+/// A virtual destructor can be declared in its place in that order with a
+/// `fn drop();` item. This expands to the *two* adjacent destructor slots
+/// that exist on `cfg(not(all(windows, target_arch = "x86")))`, or the single
+/// scalar-deleting-destructor slot on 32-bit MSVC; see [`virtual_destruct!`]
+/// for invoking it without needing to know which.
+///
+/// Besides methods, a body item can also be a non-function member stored
+/// inline among the function pointers, in whichever order the VTable
+/// actually holds them:
+/// - `const $name: $ty;` declares a plain member of type `$ty`, such as a raw
+///   `type_info` pointer.
+/// - `offset $name: $ty;` declares a member that holds the byte offset of
+///   some field in the object's body, and additionally generates a
+///   `$vt_name::$name(&obj)` accessor that turns the stored offset into a
+///   `NonNull<T>` pointing into the object.
+/// - `itanium;` opts the VTable into the Itanium C++ ABI's hidden prologue
+///   (the offset-to-top and `type_info` pointer that sit directly before the
+///   function slots), enabling
+///   [`VtObject::vtable_prologue`](crate::VtObject::vtable_prologue) and
+///   [`VtObject::downcast`](crate::VtObject::downcast). Leave this out for
+///   VTables that might run under the 32-bit MSVC ABI, which has no such
+///   prologue.
+///
 /// # Examples
 /// A simple VTable can be defined like this:
 /// ```
@@ -79,7 +99,7 @@
 /// 		pub fn curl(outer_radius: u32);
 /// 	}
 /// }
-/// 
+///
 /// /// VTable for `Snake`.
 /// #[repr(C)]
 /// pub struct SnakeVt {
@@ -87,58 +107,634 @@
 /// 	snake: SnakeVtExt
 /// }
 /// ```
+///
+/// A VTable can also be given a companion trait and a macro that constructs a
+/// `static` VTable for any Rust type implementing that trait, by adding
+/// `trait $Trait static_ctor $ctor_name` before the body:
+/// ```
+/// use cppdvt::vtable;
+///
+/// vtable! {
+/// 	/// VTable for `Pet`.
+/// 	pub PetVt trait Pet static_ctor pet_vt_static {
+/// 		pub fn speak();
+/// 	}
+/// }
+///
+/// struct Dog;
+///
+/// impl Pet for Dog {
+/// 	fn speak(&mut self) {}
+/// }
+///
+/// pet_vt_static! { DOG_VT for Dog }
+/// ```
+///
+/// Adding `pin` after `static_ctor $ctor_name` declares the object as
+/// address-sensitive: the companion trait's methods take `Pin<&mut Self>`
+/// instead of `&mut Self`. Implementors still need `Self: !Unpin` (e.g. via
+/// `PhantomPinned`) for this to actually forbid moving out of the reference,
+/// since `Pin<&mut Self>` only restricts access through safe code when `Self`
+/// isn't `Unpin`:
+/// ```
+/// use core::pin::Pin;
+///
+/// use cppdvt::vtable;
+///
+/// vtable! {
+/// 	/// VTable for `Pet`.
+/// 	pub PetVt trait Pet static_ctor pet_vt_static pin {
+/// 		pub fn speak();
+/// 	}
+/// }
+///
+/// struct Dog;
+///
+/// impl Pet for Dog {
+/// 	fn speak(self: Pin<&mut Self>) {}
+/// }
+///
+/// pet_vt_static! { DOG_VT for Dog }
+/// ```
+///
+/// A `fn drop();` item declares a virtual destructor in its place among the
+/// other methods, and [`virtual_destruct!`] calls it without needing to know
+/// the ABI-specific slot layout:
+/// ```
+/// use cppdvt::{vtable, VtObject, virtual_destruct};
+///
+/// vtable! {
+/// 	/// VTable for `Pet`.
+/// 	pub PetVt {
+/// 		pub fn speak();
+/// 		pub fn drop();
+/// 	}
+/// }
+///
+/// fn destroy(pet: &VtObject<PetVt>) {
+/// 	virtual_destruct!(*pet);
+/// }
+/// ```
+///
+/// `const`/`offset` items carry non-function data alongside the methods:
+/// ```
+/// use cppdvt::{vtable, VtObject};
+///
+/// vtable! {
+/// 	/// VTable for `Pet`.
+/// 	pub PetVt {
+/// 		/// Pointer to the `std::type_info` for this class.
+/// 		pub const type_info: *const ();
+/// 		/// Offset, in bytes, of the `Pet::name` field.
+/// 		pub offset name_field: usize;
+/// 		pub fn speak();
+/// 	}
+/// }
+///
+/// fn name_ptr(pet: &VtObject<PetVt>) -> core::ptr::NonNull<[u8; 16]> {
+/// 	PetVt::name_field(pet)
+/// }
+/// ```
+///
+/// An `itanium;` item opts the VTable into the Itanium ABI's hidden prologue,
+/// which is what [`VtObject::vtable_prologue`](crate::VtObject::vtable_prologue)
+/// and [`VtObject::downcast`](crate::VtObject::downcast) read from; VTables
+/// without it don't get these methods:
+/// ```
+/// use cppdvt::VtObject;
+///
+/// cppdvt::vtable! {
+/// 	/// VTable for `Pet`.
+/// 	pub PetVt {
+/// 		itanium;
+/// 		pub fn speak();
+/// 	}
+/// }
+///
+/// fn type_info_of(pet: &VtObject<PetVt>) -> *const () {
+/// 	unsafe { pet.vtable_prologue().type_info as *const () }
+/// }
+/// ```
+///
+/// A `fn drop();` item also lets [`VtBox`](crate::VtBox) own a Rust value
+/// behind the VTable, running the virtual destructor and freeing the backing
+/// allocation on drop. `VtBox` requires `Self` to carry its own `VTablePtr`
+/// as its first field, the same invariant
+/// [`VtObjectPtr`](crate::VtObjectPtr) itself documents:
+/// ```
+/// use cppdvt::{vtable, new_vtable_self, VTablePtr, VtObjectPtr, VtBox};
+///
+/// vtable! {
+/// 	/// VTable for `Pet`.
+/// 	pub PetVt {
+/// 		pub fn speak();
+/// 		pub fn drop();
+/// 	}
+/// }
+///
+/// #[repr(C)]
+/// struct Dog {
+/// 	vtable: VTablePtr<PetVt>,
+/// }
+///
+/// impl Dog {
+/// 	unsafe extern "C-unwind" fn speak(_this: VtObjectPtr<PetVt>) {}
+/// 	unsafe extern "C-unwind" fn complete_dtor(_this: VtObjectPtr<PetVt>) {}
+/// 	unsafe extern "C-unwind" fn deleting_dtor(_this: VtObjectPtr<PetVt>) {}
+/// }
+///
+/// static DOG_VT: PetVt = new_vtable_self!(PetVt for Dog { speak, complete_dtor, deleting_dtor });
+///
+/// let dog: VtBox<PetVt> = unsafe { VtBox::new(Dog { vtable: VTablePtr::from(&DOG_VT) }) };
+/// ```
 #[macro_export]
 macro_rules! vtable {
 	{
 		$(#[$vt_attr:meta])*
 		$vt_vis:vis $vt_name:ident for $vt_this:ty {
+			$($body:tt)*
+		}
+	} => {
+		$crate::__vtable_fields! {
+			attrs = [$(#[$vt_attr])*];
+			vis = ($vt_vis);
+			name = $vt_name;
+			this = $vt_this;
+			fields = [];
+			accessors = [];
+			destructor = [];
+			extra = [];
+			remaining = { $($body)* }
+		}
+	};
+
+	(
+		$(#[$vt_attr:meta])*
+		$vt_vis:vis $vt_name:ident {
+			$($body:tt)*
+		}
+	) => {
+		$crate::vtable! {
+			$(#[$vt_attr])*
+			$vt_vis $vt_name for $crate::VtObjectPtr<$vt_name> {
+				$($body)*
+			}
+		}
+	};
+
+	(
+		$(#[$vt_attr:meta])*
+		$vt_vis:vis $vt_name:ident trait $trait_name:ident static_ctor $ctor_name:ident for $vt_this:ty {
 			$(
 				$(#[$fn_attr:meta])*
-				$fn_vis:vis fn $fn_name:ident($($fn_param:tt)*) $(-> $fn_ret:ty)?;
+				$fn_vis:vis fn $fn_name:ident($($param_name:ident : $param_ty:ty),* $(,)?) $(-> $fn_ret:ty)?;
 			)*
 		}
-	} => {
-		$(#[$vt_attr])*
-		#[repr(C)]
-		$vt_vis struct $vt_name {
+	) => {
+		$crate::vtable! {
+			$(#[$vt_attr])*
+			$vt_vis $vt_name for $vt_this {
+				$(
+					$(#[$fn_attr])*
+					$fn_vis fn $fn_name($($param_name: $param_ty),*) $(-> $fn_ret)?;
+				)*
+			}
+		}
+
+		#[doc = concat!("Trait implemented by Rust types that back a [`", stringify!($vt_name), "`].")]
+		$vt_vis trait $trait_name {
 			$(
-				#[cfg(all(windows, target_arch = "x86"))]
 				$(#[$fn_attr])*
-				$fn_vis $fn_name:
-					unsafe extern "thiscall-unwind" fn (
-						this: $vt_this, $($fn_param)*
-					) $(-> $fn_ret)?,
-				#[cfg(not(all(windows, target_arch = "x86")))]
+				fn $fn_name(&mut self, $($param_name: $param_ty),*) $(-> $fn_ret)?;
+			)*
+		}
+
+		// Lets `obj.method()` dispatch through the `VTable` directly on any
+		// `&mut VtObject<$vt_name>` (and, through `DerefMut`, on `VtRefMut`/
+		// `VtBox`), instead of requiring `virtual_call!` at every call site.
+		impl $trait_name for $crate::VtObject<$vt_name> {
+			$(
 				$(#[$fn_attr])*
-				$fn_vis $fn_name:
-					unsafe extern "C-unwind" fn (
-						this: $vt_this, $($fn_param)*
-					) $(-> $fn_ret)?,
+				fn $fn_name(&mut self, $($param_name: $param_ty),*) $(-> $fn_ret)? {
+					$crate::virtual_call!(*self => $fn_name($($param_name),*))
+				}
 			)*
 		}
+
+		#[doc = concat!("Construct a `static` [`", stringify!($vt_name), "`] for a Rust type implementing [`", stringify!($trait_name), "`].")]
+		#[macro_export]
+		macro_rules! $ctor_name {
+			($static_name:ident for $Self:ty) => {
+				$vt_vis static $static_name: $vt_name = {
+					$(
+						#[cfg(all(windows, target_arch = "x86"))]
+						unsafe extern "thiscall-unwind" fn $fn_name(
+							this: $vt_this, $($param_name: $param_ty),*
+						) $(-> $fn_ret)? {
+							let this = this.cast::<$Self>().as_mut();
+							<$Self as $trait_name>::$fn_name(this, $($param_name),*)
+						}
+						#[cfg(not(all(windows, target_arch = "x86")))]
+						unsafe extern "C-unwind" fn $fn_name(
+							this: $vt_this, $($param_name: $param_ty),*
+						) $(-> $fn_ret)? {
+							let this = this.cast::<$Self>().as_mut();
+							<$Self as $trait_name>::$fn_name(this, $($param_name),*)
+						}
+					)*
+
+					$vt_name {
+						$($fn_name),*
+					}
+				};
+			};
+		}
 	};
 
 	(
 		$(#[$vt_attr:meta])*
-		$vt_vis:vis $vt_name:ident {
+		$vt_vis:vis $vt_name:ident trait $trait_name:ident static_ctor $ctor_name:ident {
 			$(
 				$(#[$fn_attr:meta])*
-				$fn_vis:vis fn $fn_name:ident($($fn_param:tt)*) $(-> $fn_ret:ty)?;
+				$fn_vis:vis fn $fn_name:ident($($param_name:ident : $param_ty:ty),* $(,)?) $(-> $fn_ret:ty)?;
 			)*
 		}
 	) => {
 		$crate::vtable! {
 			$(#[$vt_attr])*
-			$vt_vis $vt_name for $crate::VtObjectPtr<$vt_name> {
+			$vt_vis $vt_name trait $trait_name static_ctor $ctor_name for $crate::VtObjectPtr<$vt_name> {
 				$(
 					$(#[$fn_attr])*
-					$fn_vis fn $fn_name($($fn_param)*) $(-> $fn_ret)?;
+					$fn_vis fn $fn_name($($param_name: $param_ty),*) $(-> $fn_ret)?;
+				)*
+			}
+		}
+	};
+
+	// Same as the two arms above, but declaring the object as
+	// address-sensitive: the companion trait's methods take
+	// `Pin<&mut Self>` rather than `&mut Self`, and the generated thunks
+	// recover it by pinning the cast-back `$Self` reference directly.
+	(
+		$(#[$vt_attr:meta])*
+		$vt_vis:vis $vt_name:ident trait $trait_name:ident static_ctor $ctor_name:ident pin for $vt_this:ty {
+			$(
+				$(#[$fn_attr:meta])*
+				$fn_vis:vis fn $fn_name:ident($($param_name:ident : $param_ty:ty),* $(,)?) $(-> $fn_ret:ty)?;
+			)*
+		}
+	) => {
+		$crate::vtable! {
+			$(#[$vt_attr])*
+			$vt_vis $vt_name for $vt_this {
+				$(
+					$(#[$fn_attr])*
+					$fn_vis fn $fn_name($($param_name: $param_ty),*) $(-> $fn_ret)?;
+				)*
+			}
+		}
+
+		#[doc = concat!("Trait implemented by Rust types that back a [`", stringify!($vt_name), "`].")]
+		$vt_vis trait $trait_name {
+			$(
+				$(#[$fn_attr])*
+				fn $fn_name(self: $crate::_macro_reexport::Pin<&mut Self>, $($param_name: $param_ty),*) $(-> $fn_ret)?;
+			)*
+		}
+
+		// Lets a pinned `VtObject<$vt_name>` dispatch through the `VTable`
+		// directly, instead of requiring `virtual_call!` at every call site.
+		// The receiver is `Pin<&mut Self>`, so the call site pins first, e.g.
+		// `Pin::new(&mut *obj).method()`; a plain `obj.method()` does not apply.
+		impl $trait_name for $crate::VtObject<$vt_name> {
+			$(
+				$(#[$fn_attr])*
+				fn $fn_name(self: $crate::_macro_reexport::Pin<&mut Self>, $($param_name: $param_ty),*) $(-> $fn_ret)? {
+					$crate::virtual_call!(*self => $fn_name($($param_name),*))
+				}
+			)*
+		}
+
+		#[doc = concat!("Construct a `static` [`", stringify!($vt_name), "`] for a Rust type implementing [`", stringify!($trait_name), "`].")]
+		#[macro_export]
+		macro_rules! $ctor_name {
+			($static_name:ident for $Self:ty) => {
+				$vt_vis static $static_name: $vt_name = {
+					$(
+						#[cfg(all(windows, target_arch = "x86"))]
+						unsafe extern "thiscall-unwind" fn $fn_name(
+							this: $vt_this, $($param_name: $param_ty),*
+						) $(-> $fn_ret)? {
+							let this = $crate::_macro_reexport::Pin::new_unchecked(this.cast::<$Self>().as_mut());
+							<$Self as $trait_name>::$fn_name(this, $($param_name),*)
+						}
+						#[cfg(not(all(windows, target_arch = "x86")))]
+						unsafe extern "C-unwind" fn $fn_name(
+							this: $vt_this, $($param_name: $param_ty),*
+						) $(-> $fn_ret)? {
+							let this = $crate::_macro_reexport::Pin::new_unchecked(this.cast::<$Self>().as_mut());
+							<$Self as $trait_name>::$fn_name(this, $($param_name),*)
+						}
+					)*
+
+					$vt_name {
+						$($fn_name),*
+					}
+				};
+			};
+		}
+	};
+
+	(
+		$(#[$vt_attr:meta])*
+		$vt_vis:vis $vt_name:ident trait $trait_name:ident static_ctor $ctor_name:ident pin {
+			$(
+				$(#[$fn_attr:meta])*
+				$fn_vis:vis fn $fn_name:ident($($param_name:ident : $param_ty:ty),* $(,)?) $(-> $fn_ret:ty)?;
+			)*
+		}
+	) => {
+		$crate::vtable! {
+			$(#[$vt_attr])*
+			$vt_vis $vt_name trait $trait_name static_ctor $ctor_name pin for $crate::VtObjectPtr<$vt_name> {
+				$(
+					$(#[$fn_attr])*
+					$fn_vis fn $fn_name($($param_name: $param_ty),*) $(-> $fn_ret)?;
 				)*
 			}
 		}
 	};
 }
 
+/// Tt-muncher that builds up the field list (and any accessor methods that go
+/// with them) for a [`vtable!`]-generated struct, one body item at a time, so
+/// that methods, the special `fn drop();` destructor item, the `itanium;`
+/// marker item, and non-function `const`/`offset` members can all share the
+/// same repetition despite expanding very differently.
+///
+/// Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vtable_fields {
+	// `fn drop();`: the virtual destructor. On the Itanium ABI (i.e. everywhere
+	// but 32-bit MSVC), this is *two* adjacent slots, the complete-object
+	// destructor (D1) followed by the deleting destructor (D0); on 32-bit
+	// MSVC, it is a single scalar-deleting-destructor slot taking the flags
+	// parameter. See [`virtual_destruct!`] for calling this safely.
+	(
+		attrs = [$($vt_attr:tt)*];
+		vis = ($vt_vis:vis);
+		name = $vt_name:ident;
+		this = $vt_this:ty;
+		fields = [$($fields:tt)*];
+		accessors = [$($accessors:tt)*];
+		destructor = [$($destructor:tt)*];
+		extra = [$($extra:tt)*];
+		remaining = {
+			$(#[$d_attr:meta])*
+			$d_vis:vis fn drop();
+			$($rest:tt)*
+		}
+	) => {
+		$crate::__vtable_fields! {
+			attrs = [$($vt_attr)*];
+			vis = ($vt_vis);
+			name = $vt_name;
+			this = $vt_this;
+			fields = [
+				$($fields)*
+				#[cfg(not(all(windows, target_arch = "x86")))]
+				$(#[$d_attr])*
+				/// Complete-object destructor (D1).
+				$d_vis complete_dtor: unsafe extern "C-unwind" fn(this: $vt_this),
+				#[cfg(not(all(windows, target_arch = "x86")))]
+				$(#[$d_attr])*
+				/// Deleting destructor (D0).
+				$d_vis deleting_dtor: unsafe extern "C-unwind" fn(this: $vt_this),
+				#[cfg(all(windows, target_arch = "x86"))]
+				$(#[$d_attr])*
+				/// Scalar deleting destructor.
+				$d_vis scalar_deleting_dtor: unsafe extern "thiscall-unwind" fn(this: $vt_this, flags: u32),
+			];
+			accessors = [$($accessors)*];
+			destructor = [
+				$($destructor)*
+				/// Lets [`VtBox`](crate::VtBox) call the virtual destructor
+				/// declared above before freeing its backing storage.
+				impl $crate::HasVirtualDestructor for $vt_name {
+					unsafe fn virtual_destruct(obj: &mut $crate::VtObject<Self>) {
+						$crate::virtual_destruct!(*obj)
+					}
+				}
+			];
+			extra = [$($extra)*];
+			remaining = { $($rest)* }
+		}
+	};
+
+	// `itanium;`: opts this VTable into the Itanium ABI's hidden prologue,
+	// enabling `VtObject::vtable_prologue`/`VtObject::downcast` for it. See
+	// the `itanium` module doc for why this can't just be unconditional.
+	(
+		attrs = [$($vt_attr:tt)*];
+		vis = ($vt_vis:vis);
+		name = $vt_name:ident;
+		this = $vt_this:ty;
+		fields = [$($fields:tt)*];
+		accessors = [$($accessors:tt)*];
+		destructor = [$($destructor:tt)*];
+		extra = [$($extra:tt)*];
+		remaining = {
+			itanium;
+			$($rest:tt)*
+		}
+	) => {
+		$crate::__vtable_fields! {
+			attrs = [$($vt_attr)*];
+			vis = ($vt_vis);
+			name = $vt_name;
+			this = $vt_this;
+			fields = [$($fields)*];
+			accessors = [$($accessors)*];
+			destructor = [$($destructor)*];
+			extra = [
+				$($extra)*
+				impl $crate::ItaniumVt for $vt_name {}
+			];
+			remaining = { $($rest)* }
+		}
+	};
+
+	// `const $name: $ty;`: a non-function member stored inline among the
+	// function pointers, such as a raw `type_info` pointer.
+	(
+		attrs = [$($vt_attr:tt)*];
+		vis = ($vt_vis:vis);
+		name = $vt_name:ident;
+		this = $vt_this:ty;
+		fields = [$($fields:tt)*];
+		accessors = [$($accessors:tt)*];
+		destructor = [$($destructor:tt)*];
+		extra = [$($extra:tt)*];
+		remaining = {
+			$(#[$c_attr:meta])*
+			$c_vis:vis const $c_name:ident : $c_ty:ty;
+			$($rest:tt)*
+		}
+	) => {
+		$crate::__vtable_fields! {
+			attrs = [$($vt_attr)*];
+			vis = ($vt_vis);
+			name = $vt_name;
+			this = $vt_this;
+			fields = [
+				$($fields)*
+				$(#[$c_attr])*
+				$c_vis $c_name: $c_ty,
+			];
+			accessors = [$($accessors)*];
+			destructor = [$($destructor)*];
+			extra = [$($extra)*];
+			remaining = { $($rest)* }
+		}
+	};
+
+	// `offset $name: $ty;`: a field offset stored inline among the function
+	// pointers. Besides the struct field, this generates an associated
+	// function `$vt_name::$o_name(&VtObject<$vt_name>)` that turns the stored
+	// offset into a pointer into the object's body.
+	(
+		attrs = [$($vt_attr:tt)*];
+		vis = ($vt_vis:vis);
+		name = $vt_name:ident;
+		this = $vt_this:ty;
+		fields = [$($fields:tt)*];
+		accessors = [$($accessors:tt)*];
+		destructor = [$($destructor:tt)*];
+		extra = [$($extra:tt)*];
+		remaining = {
+			$(#[$o_attr:meta])*
+			$o_vis:vis offset $o_name:ident : $o_ty:ty;
+			$($rest:tt)*
+		}
+	) => {
+		$crate::__vtable_fields! {
+			attrs = [$($vt_attr)*];
+			vis = ($vt_vis);
+			name = $vt_name;
+			this = $vt_this;
+			fields = [
+				$($fields)*
+				$(#[$o_attr])*
+				$o_vis $o_name: $o_ty,
+			];
+			accessors = [
+				$($accessors)*
+				#[doc = concat!("Return a pointer to the `", stringify!($o_name), "` member, using the offset stored in the `VTable`.")]
+				$o_vis fn $o_name<T>(obj: &$crate::VtObject<Self>) -> $crate::_macro_reexport::NonNull<T> {
+					// SAFETY: the `VTable` is expected to hold a valid offset
+					// into the object's body, per the C++ definition of the class.
+					unsafe {
+						$crate::_macro_reexport::NonNull::new_unchecked(
+							(obj as *const $crate::VtObject<Self> as *mut u8).add($crate::VtObject::vtable(obj).$o_name as usize)
+						).cast()
+					}
+				}
+			];
+			destructor = [$($destructor)*];
+			extra = [$($extra)*];
+			remaining = { $($rest)* }
+		}
+	};
+
+	// A regular method item.
+	(
+		attrs = [$($vt_attr:tt)*];
+		vis = ($vt_vis:vis);
+		name = $vt_name:ident;
+		this = $vt_this:ty;
+		fields = [$($fields:tt)*];
+		accessors = [$($accessors:tt)*];
+		destructor = [$($destructor:tt)*];
+		extra = [$($extra:tt)*];
+		remaining = {
+			$(#[$fn_attr:meta])*
+			$fn_vis:vis fn $fn_name:ident($($param_name:ident : $param_ty:ty),* $(,)?) $(-> $fn_ret:ty)?;
+			$($rest:tt)*
+		}
+	) => {
+		$crate::__vtable_fields! {
+			attrs = [$($vt_attr)*];
+			vis = ($vt_vis);
+			name = $vt_name;
+			this = $vt_this;
+			fields = [
+				$($fields)*
+				#[cfg(all(windows, target_arch = "x86"))]
+				$(#[$fn_attr])*
+				$fn_vis $fn_name:
+					unsafe extern "thiscall-unwind" fn (
+						this: $vt_this, $($param_name: $param_ty),*
+					) $(-> $fn_ret)?,
+				#[cfg(not(all(windows, target_arch = "x86")))]
+				$(#[$fn_attr])*
+				$fn_vis $fn_name:
+					unsafe extern "C-unwind" fn (
+						this: $vt_this, $($param_name: $param_ty),*
+					) $(-> $fn_ret)?,
+			];
+			accessors = [$($accessors)*];
+			destructor = [$($destructor)*];
+			extra = [$($extra)*];
+			remaining = { $($rest)* }
+		}
+	};
+
+	// No items left: emit the struct, plus any accessors generated along the way.
+	(
+		attrs = [$($vt_attr:tt)*];
+		vis = ($vt_vis:vis);
+		name = $vt_name:ident;
+		this = $vt_this:ty;
+		fields = [$($fields:tt)*];
+		accessors = [$($accessors:tt)*];
+		destructor = [$($destructor:tt)*];
+		extra = [$($extra:tt)*];
+		remaining = {}
+	) => {
+		$($vt_attr)*
+		#[repr(C)]
+		$vt_vis struct $vt_name {
+			$($fields)*
+		}
+
+		impl $vt_name {
+			$($accessors)*
+		}
+
+		$($destructor)*
+		$($extra)*
+	};
+}
+
+/// Given `$vt_object` is a [`VtObject`](crate::VtObject) whose `VTable` was
+/// declared with a `fn drop();` destructor item (see [`vtable!`]), invoke the
+/// *complete-object* destructor for it.
+///
+/// This deliberately never calls the *deleting* destructor (which would also
+/// free the object's storage, something Rust does not expect to happen out
+/// from under it), so it is always safe to follow this up with normal Rust
+/// deallocation of the backing memory.
+#[macro_export]
+macro_rules! virtual_destruct {
+	($vt_object:expr) => {{
+		#[cfg(not(all(windows, target_arch = "x86")))]
+		{ $crate::virtual_call!($vt_object => complete_dtor()) }
+		#[cfg(all(windows, target_arch = "x86"))]
+		{ $crate::virtual_call!($vt_object => scalar_deleting_dtor(0u32)) }
+	}};
+}
+
 /// Given `$type` is a VTable type and `Self` has all of the virtual methods for
 /// that VTable with the same name, create a new VTable with those methods.
 /// 
@@ -180,6 +776,11 @@ macro_rules! new_vtable_self {
 }
 
 /// Convert the pointer `$this` to a probably-`mut` reference to `Self`.
+///
+/// The `pin`/`pin_mut` arms produce `Pin<&Self>`/`Pin<&mut Self>` instead, for
+/// address-sensitive objects declared with the `pin` option of [`vtable!`];
+/// pinning is sound here because the pointee is always behind the caller's
+/// own allocation, which `vtable!`-generated code never moves out of.
 #[macro_export]
 macro_rules! this_to_self {
 	(mut $this:expr) => {
@@ -189,6 +790,14 @@ macro_rules! this_to_self {
 	(ref $this:expr) => {
 		$this.cast::<Self>().as_ref()
 	};
+
+	(pin_mut $this:expr) => {
+		$crate::_macro_reexport::Pin::new_unchecked($this.cast::<Self>().as_mut())
+	};
+
+	(pin $this:expr) => {
+		$crate::_macro_reexport::Pin::new_unchecked($this.cast::<Self>().as_ref())
+	};
 }
 
 /// Given an invokation of the form `vt_object => name(...)`,
@@ -199,6 +808,11 @@ macro_rules! this_to_self {
 macro_rules! virtual_call {
 	($vt_object:expr => $name:ident($($arg:tt)*)) => {{
 		let vt_object = &$vt_object;
-		($crate::VtObject::vtable(vt_object).$name)($crate::VtObject::as_ptr(vt_object), $($arg)*)
+		// SAFETY: invoking a VTable function pointer is inherently unsafe
+		// (wrong ABI, wrong `this`, etc., per the C++ definition of the
+		// class); callers of this macro take on that obligation.
+		unsafe {
+			($crate::VtObject::vtable(vt_object).$name)($crate::VtObject::as_ptr(vt_object), $($arg)*)
+		}
 	}};
 }